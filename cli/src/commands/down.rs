@@ -0,0 +1,24 @@
+use crate::docker::compose::DockerCompose;
+use crate::error::Result;
+use colored::*;
+
+pub async fn execute(purge: bool) -> Result<()> {
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!("{}", "  ZecKit - Stopping Devnet".cyan().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!();
+
+    let compose = DockerCompose::new()?;
+
+    if purge {
+        println!("Stopping services and removing volumes...");
+    } else {
+        println!("Stopping services...");
+    }
+
+    compose.down(purge)?;
+
+    println!("{}", "Devnet stopped.".green());
+
+    Ok(())
+}
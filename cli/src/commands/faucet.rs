@@ -0,0 +1,29 @@
+//! `zeckit faucet` - trigger a funding transaction through the faucet API
+//! for a given address, so a test suite can request funds mid-session
+//! instead of only getting pre-mined balances out of `up`.
+
+use crate::config::Endpoints;
+use crate::docker::regtest;
+use crate::error::Result;
+use colored::*;
+use reqwest::Client;
+
+pub async fn execute(address: String, amount: f64) -> Result<()> {
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!("{}", "  ZecKit - Requesting Faucet Funds".cyan().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!();
+
+    let endpoints = Endpoints::load();
+    let client = Client::new();
+
+    println!("Requesting {} ZEC for {}...", amount, address);
+    log::info!("faucet: requesting {} ZEC for {}", amount, address);
+
+    let txid = regtest::request_faucet_funds(&client, &endpoints, &address, amount).await?;
+
+    println!("Faucet transaction: {}", txid);
+    log::info!("faucet: broadcast txid {}", txid);
+
+    Ok(())
+}
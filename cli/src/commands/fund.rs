@@ -0,0 +1,300 @@
+//! `zeckit fund` - shields matured transparent coinbase into the Sapling and
+//! Orchard pools so downstream tests have real shielded notes to spend
+//! rather than just the transparent miner balance `up` leaves behind.
+
+use crate::config::Endpoints;
+use crate::docker::regtest;
+use crate::error::{Result, ZecDevError};
+use colored::*;
+use reqwest::Client;
+use serde_json::json;
+use std::fs;
+use std::process::Command;
+use tokio::time::{sleep, Duration};
+
+/// Extra blocks mined after the shielding transaction is seen, so its notes
+/// clear the wallet's spend-confirmation threshold before balances are read.
+const CONFIRMATION_BLOCKS: u64 = 5;
+const SHIELD_TIMEOUT_SECONDS: u64 = 300;
+
+pub async fn execute() -> Result<()> {
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!("{}", "  ZecKit - Shielding Coinbase".cyan().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!();
+
+    let endpoints = Endpoints::load();
+    let backend_uri = detect_backend()?;
+    log::info!("fund: using backend {}", backend_uri);
+    println!("Using backend: {}", backend_uri);
+    println!();
+
+    println!("Deriving Sapling and Orchard receivers...");
+    let sapling_address = derive_address(&backend_uri, "sapling")?;
+    let orchard_address = derive_address(&backend_uri, "orchard")?;
+    println!("  Sapling: {}", sapling_address);
+    println!("  Orchard: {}", orchard_address);
+    println!();
+
+    println!("Shielding transparent coinbase...");
+    let txid = shield_coinbase(&backend_uri)?;
+    println!("  Shielding transaction: {}", txid);
+    println!();
+
+    println!("Waiting for shielding transaction to clear the mempool...");
+    wait_for_confirmation(&backend_uri, &txid).await?;
+    println!("  Transaction seen on chain");
+    println!();
+
+    println!("Mining {} confirmation blocks...", CONFIRMATION_BLOCKS);
+    let client = Client::new();
+    let height = regtest::mine_blocks(
+        &client,
+        &endpoints,
+        CONFIRMATION_BLOCKS,
+        Duration::from_secs(SHIELD_TIMEOUT_SECONDS),
+    )
+    .await?;
+    println!("  Reached block {}", height);
+    println!();
+
+    println!("Syncing wallet and checking shielded balances...");
+    sync_wallet(&backend_uri)?;
+    let sapling_balance = pool_balance(&backend_uri, "sapling")?;
+    let orchard_balance = pool_balance(&backend_uri, "orchard")?;
+    println!("  Sapling: {} ZEC", sapling_balance);
+    println!("  Orchard: {} ZEC", orchard_balance);
+    println!();
+
+    write_shielded_fixture(&sapling_address, sapling_balance, &orchard_address, orchard_balance)?;
+    log::info!(
+        "fund: wrote fixtures/shielded-addresses.json (sapling={} ZEC, orchard={} ZEC)",
+        sapling_balance,
+        orchard_balance
+    );
+    println!("Wrote fixtures/shielded-addresses.json");
+
+    Ok(())
+}
+
+fn detect_backend() -> Result<String> {
+    let output = Command::new("docker")
+        .args(&["ps", "--filter", "name=zeckit-zaino", "--format", "{{.Names}}"])
+        .output()
+        .map_err(|e| ZecDevError::Docker(format!("Failed to detect backend: {}", e)))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if stdout.contains("zeckit-zaino") {
+        Ok("http://zaino:9067".to_string())
+    } else {
+        let output = Command::new("docker")
+            .args(&["ps", "--filter", "name=zeckit-lightwalletd", "--format", "{{.Names}}"])
+            .output()
+            .map_err(|e| ZecDevError::Docker(format!("Failed to detect backend: {}", e)))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        if stdout.contains("zeckit-lightwalletd") {
+            Ok("http://lightwalletd:9067".to_string())
+        } else {
+            Err(ZecDevError::HealthCheck(
+                "No backend detected (neither zaino nor lightwalletd running)".into(),
+            ))
+        }
+    }
+}
+
+/// Ask zingo-cli for a new receiver in the given pool (`sapling` or
+/// `orchard`), creating one if the wallet doesn't already have one.
+fn derive_address(backend_uri: &str, pool: &str) -> Result<String> {
+    let cmd_str = format!(
+        "bash -c \"echo -e 'new {}\\nquit' | zingo-cli --data-dir /var/zingo --server {} --chain regtest --nosync 2>&1\"",
+        pool, backend_uri
+    );
+
+    let output = Command::new("docker")
+        .args(&["exec", "zeckit-zingo-wallet", "bash", "-c", &cmd_str])
+        .output()
+        .map_err(|e| ZecDevError::HealthCheck(format!("Docker exec failed: {}", e)))?;
+
+    let output_str = String::from_utf8_lossy(&output.stdout);
+    let prefix = if pool == "sapling" { "zregtest" } else { "uregtest" };
+
+    for line in output_str.lines() {
+        if line.contains(prefix) {
+            if let Some(start) = line.find(prefix) {
+                let addr_part = &line[start..];
+                let end = addr_part
+                    .find(|c: char| c == '"' || c == '\n' || c == ' ')
+                    .unwrap_or(addr_part.len());
+                let address = &addr_part[..end];
+
+                if address.len() > 30 {
+                    return Ok(address.to_string());
+                }
+            }
+        }
+    }
+
+    Err(ZecDevError::HealthCheck(format!(
+        "Could not find a {} address in zingo-cli output",
+        pool
+    )))
+}
+
+/// Shield the wallet's transparent balance into the shielded pools via
+/// zingo-cli's `shield` command, returning the broadcast transaction id.
+fn shield_coinbase(backend_uri: &str) -> Result<String> {
+    let cmd_str = format!(
+        "bash -c \"echo -e 'shield\\nquit' | zingo-cli --data-dir /var/zingo --server {} --chain regtest --nosync 2>&1\"",
+        backend_uri
+    );
+
+    let output = Command::new("docker")
+        .args(&["exec", "zeckit-zingo-wallet", "bash", "-c", &cmd_str])
+        .output()
+        .map_err(|e| ZecDevError::HealthCheck(format!("Docker exec failed: {}", e)))?;
+
+    let output_str = String::from_utf8_lossy(&output.stdout);
+
+    if output_str.contains("no transparent funds") || output_str.contains("no spendable") {
+        return Err(ZecDevError::HealthCheck(
+            "No transparent coinbase available to shield - run `zeckit up` first".into(),
+        ));
+    }
+
+    for line in output_str.lines() {
+        if line.contains("\"txid\"") {
+            if let Some(start) = line.find(':') {
+                let addr_part = &line[start + 1..];
+                let txid: String = addr_part
+                    .chars()
+                    .filter(|c| c.is_ascii_hexdigit())
+                    .collect();
+
+                if txid.len() == 64 {
+                    return Ok(txid);
+                }
+            }
+        }
+    }
+
+    Err(ZecDevError::HealthCheck(
+        "Could not find a txid in the shield command output".into(),
+    ))
+}
+
+/// Poll the wallet's sync status until `txid` is no longer pending, the way
+/// zcashd's `z_getoperationstatus` polling loop would, adapted to zingo-cli's
+/// docker-exec interface.
+async fn wait_for_confirmation(backend_uri: &str, txid: &str) -> Result<()> {
+    let start = std::time::Instant::now();
+
+    loop {
+        sync_wallet(backend_uri)?;
+
+        let cmd_str = format!(
+            "bash -c \"echo -e 'list\\nquit' | zingo-cli --data-dir /var/zingo --server {} --chain regtest --nosync 2>&1\"",
+            backend_uri
+        );
+
+        let output = Command::new("docker")
+            .args(&["exec", "zeckit-zingo-wallet", "bash", "-c", &cmd_str])
+            .output();
+
+        if let Ok(out) = output {
+            let output_str = String::from_utf8_lossy(&out.stdout);
+            if output_str.contains(txid) {
+                return Ok(());
+            }
+        }
+
+        if start.elapsed().as_secs() > SHIELD_TIMEOUT_SECONDS {
+            return Err(ZecDevError::ServiceNotReady(
+                "Shielding transaction did not appear on chain in time".into(),
+            ));
+        }
+
+        sleep(Duration::from_secs(3)).await;
+    }
+}
+
+fn sync_wallet(backend_uri: &str) -> Result<()> {
+    let cmd_str = format!(
+        "bash -c \"echo -e 'sync run\\nquit' | zingo-cli --data-dir /var/zingo --server {} --chain regtest 2>&1\"",
+        backend_uri
+    );
+
+    Command::new("docker")
+        .args(&["exec", "-i", "zeckit-zingo-wallet", "bash", "-c", &cmd_str])
+        .output()
+        .map_err(|e| ZecDevError::HealthCheck(format!("Sync command failed: {}", e)))?;
+
+    Ok(())
+}
+
+/// Read a pool's confirmed balance out of zingo-cli's `balance` command.
+fn pool_balance(backend_uri: &str, pool: &str) -> Result<f64> {
+    let cmd_str = format!(
+        "bash -c \"echo -e 'balance\\nquit' | zingo-cli --data-dir /var/zingo --server {} --chain regtest --nosync 2>&1\"",
+        backend_uri
+    );
+
+    let output = Command::new("docker")
+        .args(&["exec", "zeckit-zingo-wallet", "bash", "-c", &cmd_str])
+        .output()
+        .map_err(|e| ZecDevError::HealthCheck(format!("Docker exec failed: {}", e)))?;
+
+    let output_str = String::from_utf8_lossy(&output.stdout);
+    let field = if pool == "sapling" {
+        "sapling_balance"
+    } else {
+        "orchard_balance"
+    };
+
+    for line in output_str.lines() {
+        if line.contains(field) {
+            if let Some(start) = line.find(':') {
+                let value_part = &line[start + 1..];
+                let digits: String = value_part
+                    .chars()
+                    .filter(|c| c.is_ascii_digit() || *c == '.')
+                    .collect();
+
+                if let Ok(zatoshis) = digits.parse::<f64>() {
+                    return Ok(zatoshis / 100_000_000.0);
+                }
+            }
+        }
+    }
+
+    Ok(0.0)
+}
+
+fn write_shielded_fixture(
+    sapling_address: &str,
+    sapling_balance: f64,
+    orchard_address: &str,
+    orchard_balance: f64,
+) -> Result<()> {
+    let fixture = json!({
+        "sapling": {
+            "address": sapling_address,
+            "balance": sapling_balance,
+        },
+        "orchard": {
+            "address": orchard_address,
+            "balance": orchard_balance,
+        },
+        "note": "Shielded addresses and confirmed balances written by `zeckit fund`",
+    });
+
+    fs::create_dir_all("fixtures")?;
+    fs::write(
+        "fixtures/shielded-addresses.json",
+        serde_json::to_string_pretty(&fixture)?,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! `zeckit generate` - mine exactly N blocks and block until they confirm,
+//! so a test suite can step the chain deterministically mid-session instead
+//! of relying on the one-shot maturity wait baked into `up`.
+
+use crate::config::Endpoints;
+use crate::docker::regtest;
+use crate::error::Result;
+use colored::*;
+use reqwest::Client;
+use tokio::time::Duration;
+
+const GENERATE_TIMEOUT_SECONDS: u64 = 600;
+
+pub async fn execute(count: u64) -> Result<()> {
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!("{}", "  ZecKit - Generating Blocks".cyan().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!();
+
+    let endpoints = Endpoints::load();
+    let client = Client::new();
+
+    println!("Mining {} block(s)...", count);
+    log::info!("generate: mining {} block(s)", count);
+
+    let height = regtest::mine_blocks(
+        &client,
+        &endpoints,
+        count,
+        Duration::from_secs(GENERATE_TIMEOUT_SECONDS),
+    )
+    .await?;
+
+    println!("Mined to block height {}", height);
+    log::info!("generate: reached block height {}", height);
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+pub mod down;
+pub mod faucet;
+pub mod fund;
+pub mod generate;
+pub mod serve;
+pub mod status;
+pub mod test;
+pub mod up;
@@ -0,0 +1,187 @@
+//! `zeckit serve` - expose devnet control over a small local JSON-RPC 2.0
+//! over HTTP server, so other tools and integration-test suites can drive
+//! the regtest network in-process or over a socket and assert on its state,
+//! rather than shelling out to `zeckit` and scraping stdout.
+//!
+//! This is a deliberately minimal hand-rolled listener rather than a web
+//! framework dependency - one `TcpListener`, one method dispatch - matching
+//! the rest of the toolkit's habit of reaching for `std::process`/`tokio`
+//! primitives directly.
+
+use crate::config::Endpoints;
+use crate::docker::compose::DockerCompose;
+use crate::docker::regtest;
+use crate::error::{Result, ZecDevError};
+use colored::*;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::Duration;
+
+pub async fn execute(port: u16) -> Result<()> {
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!("{}", "  ZecKit - RPC Control Server".cyan().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!();
+
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+
+    println!("Listening on http://127.0.0.1:{}", port);
+    println!("POST JSON-RPC 2.0 requests to / - methods: status, up, down, generate, faucet");
+    println!();
+    log::info!("serve: listening on 127.0.0.1:{}", port);
+
+    loop {
+        let (stream, addr) = listener.accept().await?;
+        log::debug!("serve: accepted connection from {}", addr);
+
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream).await {
+                log::warn!("serve: connection from {} errored: {}", addr, e);
+            }
+        });
+    }
+}
+
+async fn handle_connection(mut stream: TcpStream) -> Result<()> {
+    let request = read_http_request(&mut stream).await?;
+    let rpc_request: Value = serde_json::from_str(&request)
+        .map_err(|e| ZecDevError::HealthCheck(format!("invalid JSON-RPC request: {}", e)))?;
+
+    let id = rpc_request.get("id").cloned().unwrap_or(Value::Null);
+    let method = rpc_request
+        .get("method")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    let params = rpc_request.get("params").cloned().unwrap_or(Value::Null);
+
+    log::info!("serve: dispatching method {}", method);
+
+    let body = match dispatch(method, &params).await {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32000, "message": e.to_string() }
+        }),
+    };
+
+    write_http_response(&mut stream, &body.to_string()).await
+}
+
+async fn dispatch(method: &str, params: &Value) -> Result<Value> {
+    let endpoints = Endpoints::load();
+    let client = Client::new();
+
+    match method {
+        "status" => {
+            let compose = DockerCompose::new()?;
+            Ok(json!({
+                "running": compose.is_running(),
+                "services": compose.ps().unwrap_or_default(),
+            }))
+        }
+        "up" => {
+            let backend = params
+                .get("backend")
+                .and_then(|v| v.as_str())
+                .unwrap_or("none")
+                .to_string();
+            let fresh = params
+                .get("fresh")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            crate::commands::up::execute(backend, fresh).await?;
+            Ok(json!({ "started": true }))
+        }
+        "down" => {
+            let purge = params
+                .get("purge")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            crate::commands::down::execute(purge).await?;
+            Ok(json!({ "stopped": true }))
+        }
+        "generate" => {
+            let count = params
+                .get("count")
+                .and_then(|v| v.as_u64())
+                .ok_or_else(|| ZecDevError::Config("generate requires a \"count\" param".into()))?;
+
+            let height =
+                regtest::mine_blocks(&client, &endpoints, count, Duration::from_secs(600)).await?;
+            Ok(json!({ "height": height }))
+        }
+        "faucet" => {
+            let address = params
+                .get("address")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| ZecDevError::Config("faucet requires an \"address\" param".into()))?;
+            let amount = params
+                .get("amount")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| ZecDevError::Config("faucet requires an \"amount\" param".into()))?;
+
+            let txid = regtest::request_faucet_funds(&client, &endpoints, address, amount).await?;
+            Ok(json!({ "txid": txid }))
+        }
+        other => Err(ZecDevError::Config(format!("unknown RPC method: {}", other))),
+    }
+}
+
+/// Read one HTTP/1.1 request off `stream` and return its body. Header
+/// parsing only needs `Content-Length` - this server has no clients besides
+/// simple JSON-RPC callers, so it doesn't need a general-purpose HTTP parser.
+async fn read_http_request(stream: &mut TcpStream) -> Result<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let headers_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Err(ZecDevError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "connection closed before headers were complete",
+            )));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+
+        if let Some(pos) = find_subslice(&buf, b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let header_text = String::from_utf8_lossy(&buf[..headers_end]);
+    let content_length: usize = header_text
+        .lines()
+        .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|v| v.trim().to_string()))
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+
+    while buf.len() < headers_end + content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    Ok(String::from_utf8_lossy(&buf[headers_end..headers_end + content_length]).to_string())
+}
+
+async fn write_http_response(stream: &mut TcpStream, body: &str) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
@@ -0,0 +1,23 @@
+use crate::docker::compose::DockerCompose;
+use crate::error::Result;
+use colored::*;
+
+pub async fn execute() -> Result<()> {
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!("{}", "  ZecKit - Devnet Status".cyan().bold());
+    println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
+    println!();
+
+    let compose = DockerCompose::new()?;
+
+    if !compose.is_running() {
+        println!("{}", "Devnet is not running.".yellow());
+        return Ok(());
+    }
+
+    for line in compose.ps()? {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
@@ -1,8 +1,11 @@
+use crate::config::Endpoints;
+use crate::docker::backend::{self, Backend};
 use crate::docker::compose::DockerCompose;
-use crate::docker::health::HealthChecker;
+use crate::docker::health::{HealthChecker, ServiceKind};
+use crate::docker::regtest::get_block_count;
 use crate::error::{Result, ZecDevError};
 use colored::*;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use reqwest::Client;
 use serde_json::json;
 use std::process::Command;
@@ -19,50 +22,34 @@ pub async fn execute(backend: String, fresh: bool) -> Result<()> {
     println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
     println!();
     
+    let backend: Box<dyn Backend> = backend::parse_backend(&backend)?;
+    log::info!("up: backend={} fresh={}", backend.flag(), fresh);
+
     let compose = DockerCompose::new()?;
-    
+    let endpoints = Endpoints::load();
+
     if fresh {
+        log::info!("up: fresh start requested, tearing down existing volumes");
         println!("{}", "Cleaning up old data...".yellow());
         compose.down(true)?;
     }
-    
-    let services = match backend.as_str() {
-        "lwd" => vec!["zebra", "faucet"],
-        "zaino" => vec!["zebra", "faucet"],
-        "none" => vec!["zebra", "faucet"],
-        _ => {
-            return Err(ZecDevError::Config(format!(
-                "Invalid backend: {}. Use 'lwd', 'zaino', or 'none'", 
-                backend
-            )));
-        }
-    };
-    
+
+    let services = vec!["zebra", "faucet"];
+
     println!("Starting services: {}", services.join(", "));
     println!();
-    
+
     // Build and start services with progress
-    if backend == "lwd" {
-        println!("Building Docker images...");
-        println!();
-        
-        println!("[1/4] Building Zebra...");
-        println!("[2/4] Building Lightwalletd...");
-        println!("[3/4] Building Zingo Wallet...");
-        println!("[4/4] Building Faucet...");
-        
-        compose.up_with_profile("lwd")?;
-        println!();
-    } else if backend == "zaino" {
+    if let Some(profile) = backend.compose_profile() {
         println!("Building Docker images...");
         println!();
-        
+
         println!("[1/4] Building Zebra...");
-        println!("[2/4] Building Zaino...");
+        println!("[2/4] Building {}...", backend.name());
         println!("[3/4] Building Zingo Wallet...");
         println!("[4/4] Building Faucet...");
-        
-        compose.up_with_profile("zaino")?;
+
+        compose.up_with_profile(profile)?;
         println!();
     } else {
         compose.up(&services)?;
@@ -70,118 +57,76 @@ pub async fn execute(backend: String, fresh: bool) -> Result<()> {
     
     println!("Starting services...");
     println!();
-    
+
+    let checker = HealthChecker::new()
+        .with_compose(compose.clone())
+        .with_endpoints(endpoints.clone());
+
+    // [1/3] Zebra, the backend, and the faucet don't depend on one another,
+    // so wait for all three concurrently instead of one after another -
+    // this is the same total wait either way but a multi-minute backend
+    // sync no longer blocks Zebra/Faucet from reporting ready sooner.
+    let mut services = vec![ServiceKind::Zebra, ServiceKind::Faucet];
+    if backend.compose_profile().is_some() {
+        services.push(ServiceKind::Backend(backend.flag().to_string()));
+    }
+
+    let labels: Vec<String> = services.iter().map(|s| s.label()).collect();
+    println!("[1/3] Waiting for {}...", labels.join(", "));
+    let start = std::time::Instant::now();
+
+    let multi = MultiProgress::new();
+    checker.wait_for_all(&services, &multi).await?;
+
+    println!("[1/3] All services ready ({:.0}s)", start.elapsed().as_secs_f64());
+    log::info!("up: all services ready after {}s", start.elapsed().as_secs());
+    println!();
+
+    // Version-compatibility gate - fail fast before declaring the stack
+    // healthy rather than letting a too-old/mismatched node limp along.
+    let versions = checker.check_versions(backend.flag()).await?;
+    println!("Zebra version: {}", versions.zebra_version);
+    if let Some(backend_version) = &versions.backend_version {
+        println!("{} version: {}", versions.backend_name, backend_version);
+    }
+    log::info!("up: version check passed (zebra={})", versions.zebra_version);
+    println!();
+
+    // [2/3] Wallet with percentage (EXTENDED TIMEOUT)
+    let backend_uri = backend.internal_uri();
+
     let pb = ProgressBar::new_spinner();
     pb.set_style(
         ProgressStyle::default_spinner()
             .template("{spinner:.green} {msg}")
             .unwrap()
     );
-    
-    // [1/4] Zebra with percentage
-    let checker = HealthChecker::new();
-    let start = std::time::Instant::now();
-    
-    loop {
-        pb.tick();
-        
-        if checker.wait_for_zebra(&pb).await.is_ok() {
-            println!("[1/4] Zebra ready (100%)");
-            break;
-        }
-        
-        let elapsed = start.elapsed().as_secs();
-        if elapsed < 120 {
-            let progress = (elapsed as f64 / 120.0 * 100.0).min(99.0) as u32;
-            print!("\r[1/4] Starting Zebra... {}%", progress);
-            io::stdout().flush().ok();
-            sleep(Duration::from_secs(1)).await;
-        } else {
-            return Err(ZecDevError::ServiceNotReady("Zebra not ready".into()));
-        }
-    }
-    println!();
-    
-    // [2/4] Backend with percentage
-    if backend == "lwd" || backend == "zaino" {
-        let backend_name = if backend == "lwd" { "Lightwalletd" } else { "Zaino" };
-        let start = std::time::Instant::now();
-        
-        loop {
-            pb.tick();
-            
-            if checker.wait_for_backend(&backend, &pb).await.is_ok() {
-                println!("[2/4] {} ready (100%)", backend_name);
-                break;
-            }
-            
-            let elapsed = start.elapsed().as_secs();
-            if elapsed < 180 {
-                let progress = (elapsed as f64 / 180.0 * 100.0).min(99.0) as u32;
-                print!("\r[2/4] Starting {}... {}%", backend_name, progress);
-                io::stdout().flush().ok();
-                sleep(Duration::from_secs(1)).await;
-            } else {
-                return Err(ZecDevError::ServiceNotReady(format!("{} not ready", backend_name)));
-            }
-        }
-        println!();
-    }
-    
-    // [3/4] Wallet with percentage (EXTENDED TIMEOUT)
-    let backend_uri = if backend == "lwd" {
-        "http://lightwalletd:9067"
-    } else if backend == "zaino" {
-        "http://zaino:9067"
-    } else {
-        "http://lightwalletd:9067"
-    };
-    
+
     let start = std::time::Instant::now();
     loop {
         pb.tick();
-        
+
         if wait_for_wallet_ready(&pb, backend_uri).await.is_ok() {
-            println!("[3/4] Zingo Wallet ready (100%)");
+            println!("[2/3] Zingo Wallet ready (100%)");
+            log::info!("up: Zingo Wallet ready after {}s", start.elapsed().as_secs());
             break;
         }
-        
+
         let elapsed = start.elapsed().as_secs();
         if elapsed < WALLET_TIMEOUT_SECONDS {
             let progress = (elapsed as f64 / WALLET_TIMEOUT_SECONDS as f64 * 100.0).min(99.0) as u32;
-            print!("\r[3/4] Starting Zingo Wallet... {}%", progress);
+            print!("\r[2/3] Starting Zingo Wallet... {}%", progress);
             io::stdout().flush().ok();
             sleep(Duration::from_secs(1)).await;
         } else {
+            log::error!("up: Zingo Wallet not ready after {}s", elapsed);
             return Err(ZecDevError::ServiceNotReady("Wallet not ready after 100 minutes".into()));
         }
     }
     println!();
-    
-    // [4/4] Faucet with percentage
-    let start = std::time::Instant::now();
-    loop {
-        pb.tick();
-        
-        if checker.wait_for_faucet(&pb).await.is_ok() {
-            println!("[4/4] Faucet ready (100%)");
-            break;
-        }
-        
-        let elapsed = start.elapsed().as_secs();
-        if elapsed < 60 {
-            let progress = (elapsed as f64 / 60.0 * 100.0).min(99.0) as u32;
-            print!("\r[4/4] Starting Faucet... {}%", progress);
-            io::stdout().flush().ok();
-            sleep(Duration::from_secs(1)).await;
-        } else {
-            return Err(ZecDevError::ServiceNotReady("Faucet not ready".into()));
-        }
-    }
-    println!();
-    
+
     pb.finish_and_clear();
-    
+
     // GET WALLET ADDRESS AND UPDATE ZEBRA CONFIG
     println!();
     println!("Configuring Zebra to mine to wallet...");
@@ -189,26 +134,30 @@ pub async fn execute(backend: String, fresh: bool) -> Result<()> {
     match get_wallet_transparent_address(backend_uri).await {
         Ok(t_address) => {
             println!("Wallet transparent address: {}", t_address);
-            
+            log::info!("up: mining to wallet address {}", t_address);
+
             if let Err(e) = update_zebra_miner_address(&t_address) {
+                log::warn!("up: could not update zebra.toml: {}", e);
                 println!("{}", format!("Warning: Could not update zebra.toml: {}", e).yellow());
             } else {
                 println!("Updated zebra.toml miner_address");
-                
+
                 println!("Restarting Zebra with new miner address...");
                 if let Err(e) = restart_zebra().await {
+                    log::warn!("up: Zebra restart had issues: {}", e);
                     println!("{}", format!("Warning: Zebra restart had issues: {}", e).yellow());
                 }
             }
         }
         Err(e) => {
+            log::warn!("up: could not get wallet address: {}", e);
             println!("{}", format!("Warning: Could not get wallet address: {}", e).yellow());
             println!("  Mining will use default address in zebra.toml");
         }
     }
     
     // NOW WAIT FOR BLOCKS (mining to correct address)
-    wait_for_mined_blocks(&pb, 101).await?;
+    wait_for_mined_blocks(101, &endpoints).await?;
     
     // Wait extra time for coinbase maturity
     println!();
@@ -222,27 +171,32 @@ pub async fn execute(backend: String, fresh: bool) -> Result<()> {
     match generate_ua_fixtures(backend_uri).await {
         Ok(address) => {
             println!("Generated UA: {}...", &address[..20]);
+            log::info!("up: generated UA fixture {}", address);
         }
         Err(e) => {
+            log::warn!("up: could not generate UA fixture: {}", e);
             println!("{}", format!("Warning: Could not generate UA fixture ({})", e).yellow());
             println!("  You can manually update fixtures/unified-addresses.json");
         }
     }
-    
+
     // Sync wallet
     println!();
     println!("Syncing wallet with blockchain...");
     if let Err(e) = sync_wallet(backend_uri).await {
+        log::warn!("up: wallet sync warning: {}", e);
         println!("{}", format!("Wallet sync warning: {}", e).yellow());
     } else {
+        log::info!("up: wallet synced with blockchain");
         println!("Wallet synced with blockchain");
     }
-    
+
     // Check balance
     println!();
     println!("Checking wallet balance...");
-    match check_wallet_balance().await {
+    match check_wallet_balance(&endpoints).await {
         Ok(balance) if balance > 0.0 => {
+            log::info!("up: wallet balance {} ZEC", balance);
             println!("Wallet has {} ZEC available", balance);
         }
         Ok(_) => {
@@ -250,12 +204,15 @@ pub async fn execute(backend: String, fresh: bool) -> Result<()> {
             println!("  Blocks still maturing, wait a few more minutes");
         }
         Err(e) => {
+            log::warn!("up: could not check balance: {}", e);
             println!("{}", format!("Could not check balance: {}", e).yellow());
         }
     }
+
+    log::info!("up: devnet ready");
     
-    print_connection_info(&backend);
-    print_mining_info().await?;
+    print_connection_info(backend.as_ref(), &endpoints);
+    print_mining_info(&endpoints).await?;
     
     Ok(())
 }
@@ -290,17 +247,19 @@ async fn wait_for_wallet_ready(pb: &ProgressBar, backend_uri: &str) -> Result<()
     }
 }
 
-async fn wait_for_mined_blocks(pb: &ProgressBar, min_blocks: u64) -> Result<()> {
+async fn wait_for_mined_blocks(min_blocks: u64, endpoints: &Endpoints) -> Result<()> {
     let client = Client::new();
     let start = std::time::Instant::now();
-    
+
     println!("Mining blocks to maturity...");
-    
+    log::info!("up: mining to {} blocks", min_blocks);
+
     loop {
-        match get_block_count(&client).await {
+        match get_block_count(&client, endpoints).await {
             Ok(height) if height >= min_blocks => {
                 println!("Mined {} blocks (coinbase maturity reached)", height);
                 println!();
+                log::info!("up: mined {} blocks, coinbase maturity reached", height);
                 return Ok(());
             }
             Ok(height) => {
@@ -310,8 +269,9 @@ async fn wait_for_mined_blocks(pb: &ProgressBar, min_blocks: u64) -> Result<()>
             }
             Err(_) => {}
         }
-        
+
         if start.elapsed().as_secs() > MAX_WAIT_SECONDS {
+            log::error!("up: internal miner timeout after {}s", start.elapsed().as_secs());
             return Err(ZecDevError::ServiceNotReady(
                 "Internal miner timeout - blocks not reaching maturity".into()
             ));
@@ -321,26 +281,6 @@ async fn wait_for_mined_blocks(pb: &ProgressBar, min_blocks: u64) -> Result<()>
     }
 }
 
-async fn get_block_count(client: &Client) -> Result<u64> {
-    let resp = client
-        .post("http://127.0.0.1:8232")
-        .json(&json!({
-            "jsonrpc": "2.0",
-            "id": "blockcount",
-            "method": "getblockcount",
-            "params": []
-        }))
-        .timeout(Duration::from_secs(5))
-        .send()
-        .await?;
-    
-    let json: serde_json::Value = resp.json().await?;
-    
-    json.get("result")
-        .and_then(|v| v.as_u64())
-        .ok_or_else(|| ZecDevError::HealthCheck("Invalid block count response".into()))
-}
-
 async fn get_wallet_transparent_address(backend_uri: &str) -> Result<String> {
     let cmd_str = format!(
         "bash -c \"echo -e 't_addresses\\nquit' | zingo-cli --data-dir /var/zingo --server {} --chain regtest --nosync 2>&1\"",
@@ -475,10 +415,10 @@ async fn sync_wallet(backend_uri: &str) -> Result<()> {
     }
 }
 
-async fn check_wallet_balance() -> Result<f64> {
+async fn check_wallet_balance(endpoints: &Endpoints) -> Result<f64> {
     let client = Client::new();
     let resp = client
-        .get("http://127.0.0.1:8080/stats")
+        .get(format!("{}/stats", endpoints.faucet_http))
         .timeout(Duration::from_secs(5))
         .send()
         .await?;
@@ -487,10 +427,10 @@ async fn check_wallet_balance() -> Result<f64> {
     Ok(json["current_balance"].as_f64().unwrap_or(0.0))
 }
 
-async fn print_mining_info() -> Result<()> {
+async fn print_mining_info(endpoints: &Endpoints) -> Result<()> {
     let client = Client::new();
-    
-    if let Ok(height) = get_block_count(&client).await {
+
+    if let Ok(height) = get_block_count(&client, endpoints).await {
         println!();
         println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
         println!("{}", "  Blockchain Status".cyan().bold());
@@ -505,21 +445,17 @@ async fn print_mining_info() -> Result<()> {
     Ok(())
 }
 
-fn print_connection_info(backend: &str) {
+fn print_connection_info(backend: &dyn Backend, endpoints: &Endpoints) {
     println!();
     println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
     println!("{}", "  Services Ready".cyan().bold());
     println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
     println!();
-    println!("  Zebra RPC: http://127.0.0.1:8232");
-    println!("  Faucet API: http://127.0.0.1:8080");
-    
-    if backend == "lwd" {
-        println!("  LightwalletD: http://127.0.0.1:9067");
-    } else if backend == "zaino" {
-        println!("  Zaino: http://127.0.0.1:9067");
-    }
-    
+    println!("  Zebra RPC: {}", endpoints.zebra_rpc);
+    println!("  Faucet API: {}", endpoints.faucet_http);
+
+    backend.print_connection_line(endpoints);
+
     println!();
     println!("Next steps:");
     println!("  • Run tests: zecdev test");
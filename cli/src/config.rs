@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use std::env;
+
+/// Host-facing addresses ZecKit probes for readiness/testing. Every caller
+/// that previously hardcoded `127.0.0.1:8232` / `:8080` / `:9067` should go
+/// through this struct instead, so remapped ports, a remote host, or
+/// multiple side-by-side regtest environments just become a different
+/// `Endpoints` value rather than a source change.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct Endpoints {
+    pub zebra_rpc: String,
+    pub faucet_http: String,
+    pub backend_grpc: String,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self {
+            zebra_rpc: "http://127.0.0.1:8232".to_string(),
+            faucet_http: "http://127.0.0.1:8080".to_string(),
+            backend_grpc: "http://127.0.0.1:9067".to_string(),
+        }
+    }
+}
+
+impl Endpoints {
+    /// Resolve endpoints in priority order: env vars, then a `zeckit.toml`
+    /// config file in the project root, then the defaults above.
+    pub fn load() -> Self {
+        let mut endpoints = Self::from_config_file().unwrap_or_default();
+
+        if let Ok(v) = env::var("ZECKIT_ZEBRA_RPC") {
+            endpoints.zebra_rpc = v;
+        }
+        if let Ok(v) = env::var("ZECKIT_FAUCET_HTTP") {
+            endpoints.faucet_http = v;
+        }
+        if let Ok(v) = env::var("ZECKIT_BACKEND_GRPC") {
+            endpoints.backend_grpc = v;
+        }
+
+        endpoints
+    }
+
+    fn from_config_file() -> Option<Self> {
+        let content = std::fs::read_to_string("zeckit.toml").ok()?;
+        toml::from_str(&content).ok()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::docker::compose::DockerCompose;
+use crate::error::Result;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const LOG_TAIL_LINES: usize = 100;
+
+/// Dump the last [`LOG_TAIL_LINES`] of each `service`'s logs to a timestamped
+/// file under `diagnostics/` and return its path. Called from the
+/// `wait_for_*`/smoke-test failure paths so a timeout leaves behind a
+/// self-service debugging bundle instead of just a terse error message.
+pub fn capture_logs(compose: &DockerCompose, services: &[&str]) -> Result<std::path::PathBuf> {
+    fs::create_dir_all("diagnostics")?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let path = std::path::PathBuf::from(format!("diagnostics/{}-failure.log", timestamp));
+
+    let mut bundle = String::new();
+    for service in services {
+        bundle.push_str(&format!("==== {} (last {} lines) ====\n", service, LOG_TAIL_LINES));
+        match compose.logs(service, LOG_TAIL_LINES) {
+            Ok(lines) => {
+                bundle.push_str(&lines.join("\n"));
+                bundle.push('\n');
+            }
+            Err(e) => {
+                bundle.push_str(&format!("<could not fetch logs: {}>\n", e));
+            }
+        }
+        bundle.push('\n');
+    }
+
+    fs::write(&path, bundle)?;
+
+    Ok(path)
+}
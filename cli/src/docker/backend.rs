@@ -0,0 +1,118 @@
+//! Pluggable light-client backends for `zeckit up`/`test`.
+//!
+//! Previously the supported backends (`lwd`, `zaino`, `none`) were threaded
+//! through call sites as a raw `String`, re-matched wherever behaviour
+//! differed (which compose profile to bring up, which internal URI to hand
+//! `zingo-cli`, what to print in the connection banner). This module parses
+//! the `--backend` flag once into a concrete [`Backend`] implementor, so
+//! adding a new backend means adding an impl here rather than hunting down
+//! every `match backend.as_str()`.
+
+use crate::config::Endpoints;
+use crate::error::{Result, ZecDevError};
+
+pub trait Backend {
+    /// Value accepted by `--backend` this implementor was parsed from.
+    fn flag(&self) -> &'static str;
+
+    /// Human-readable name for progress messages and banners.
+    fn name(&self) -> &'static str;
+
+    /// `docker-compose.yml` profile to bring up, if this backend runs as a
+    /// compose service. `None` means no extra service is started.
+    fn compose_profile(&self) -> Option<&'static str>;
+
+    /// gRPC service URI as seen from inside the docker network, passed to
+    /// `zingo-cli --server`.
+    fn internal_uri(&self) -> &'static str;
+
+    /// Print this backend's line of the "Services Ready" banner, if it has
+    /// a user-facing endpoint to report.
+    fn print_connection_line(&self, _endpoints: &Endpoints) {}
+}
+
+pub struct Lightwalletd;
+
+impl Backend for Lightwalletd {
+    fn flag(&self) -> &'static str {
+        "lwd"
+    }
+
+    fn name(&self) -> &'static str {
+        "Lightwalletd"
+    }
+
+    fn compose_profile(&self) -> Option<&'static str> {
+        Some("lwd")
+    }
+
+    fn internal_uri(&self) -> &'static str {
+        "http://lightwalletd:9067"
+    }
+
+    fn print_connection_line(&self, endpoints: &Endpoints) {
+        println!("  LightwalletD: {}", endpoints.backend_grpc);
+    }
+}
+
+pub struct Zaino;
+
+impl Backend for Zaino {
+    fn flag(&self) -> &'static str {
+        "zaino"
+    }
+
+    fn name(&self) -> &'static str {
+        "Zaino"
+    }
+
+    fn compose_profile(&self) -> Option<&'static str> {
+        Some("zaino")
+    }
+
+    fn internal_uri(&self) -> &'static str {
+        "http://zaino:9067"
+    }
+
+    fn print_connection_line(&self, endpoints: &Endpoints) {
+        println!("  Zaino: {}", endpoints.backend_grpc);
+    }
+}
+
+/// No light-client backend at all - Zebra and the faucet run on their own,
+/// and anything that shells out to `zingo-cli` falls back to lightwalletd's
+/// well-known URI even though nothing is listening there. That's an existing
+/// quirk of `none`, not something introduced here.
+pub struct NoBackend;
+
+impl Backend for NoBackend {
+    fn flag(&self) -> &'static str {
+        "none"
+    }
+
+    fn name(&self) -> &'static str {
+        "none"
+    }
+
+    fn compose_profile(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn internal_uri(&self) -> &'static str {
+        "http://lightwalletd:9067"
+    }
+}
+
+/// Parse the `--backend` flag into a concrete `Backend` once, instead of
+/// re-matching the raw string at every call site that needs to know about it.
+pub fn parse_backend(flag: &str) -> Result<Box<dyn Backend>> {
+    match flag {
+        "lwd" => Ok(Box::new(Lightwalletd)),
+        "zaino" => Ok(Box::new(Zaino)),
+        "none" => Ok(Box::new(NoBackend)),
+        other => Err(ZecDevError::Config(format!(
+            "Invalid backend: {}. Use 'lwd', 'zaino', or 'none'",
+            other
+        ))),
+    }
+}
@@ -0,0 +1,48 @@
+use crate::error::{Result, ZecDevError};
+use semver::{Version, VersionReq};
+
+/// Minimum/maximum supported version per component, expressed as a semver
+/// requirement. Keep this table as the single place to bump when a new
+/// Zebra/lightwalletd/zaino release raises or lowers the floor.
+const SUPPORTED_RANGES: &[(&str, &str)] = &[
+    ("zebra", ">=1.4.0, <2.0.0"),
+    ("lightwalletd", ">=0.4.0, <1.0.0"),
+    ("zaino", ">=0.1.0, <1.0.0"),
+];
+
+/// Node/backend version strings in the wild aren't strict semver (a leading
+/// "v", or trailing build metadata like "+abc123"), so parsing is best-effort
+/// rather than a hard `Version::parse` that rejects anything unexpected.
+fn parse_version(raw: &str) -> Result<Version> {
+    let trimmed = raw.trim().trim_start_matches('v');
+    let core: String = trimmed
+        .split(|c: char| c != '.' && !c.is_ascii_digit())
+        .take(1)
+        .collect();
+
+    Version::parse(&core)
+        .map_err(|e| ZecDevError::Config(format!("could not parse version '{}': {}", raw, e)))
+}
+
+/// Fail fast with a clear "X is below/above the supported range" message if
+/// `component`'s detected version falls outside its declared range.
+pub fn check_version(component: &str, detected_version: &str) -> Result<()> {
+    let (_, range) = SUPPORTED_RANGES
+        .iter()
+        .find(|(name, _)| *name == component)
+        .ok_or_else(|| ZecDevError::Config(format!("no supported range declared for {}", component)))?;
+
+    let req = VersionReq::parse(range)
+        .map_err(|e| ZecDevError::Config(format!("invalid version requirement for {}: {}", component, e)))?;
+
+    let version = parse_version(detected_version)?;
+
+    if req.matches(&version) {
+        Ok(())
+    } else {
+        Err(ZecDevError::Config(format!(
+            "{} {} is outside the supported range {}",
+            component, version, range
+        )))
+    }
+}
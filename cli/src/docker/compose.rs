@@ -1,5 +1,8 @@
 use crate::error::{Result, ZecDevError};
-use std::process::Command;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
 
 #[derive(Clone)]
 pub struct DockerCompose {
@@ -140,6 +143,40 @@ impl DockerCompose {
         Ok(lines)
     }
 
+    /// Stream `docker compose logs -f <service>` line by line over a
+    /// channel instead of blocking for the full output, so a future
+    /// `--follow` mode can tail logs live during a long sync. The spawned
+    /// child is reaped on a background thread once the log stream ends.
+    pub fn logs_follow(&self, service: &str) -> Result<Receiver<String>> {
+        let mut child = Command::new("docker")
+            .arg("compose")
+            .arg("logs")
+            .arg("-f")
+            .arg(service)
+            .current_dir(&self.project_dir)
+            .stdout(Stdio::piped())
+            .spawn()?;
+
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| ZecDevError::Docker("failed to capture log stream stdout".into()))?;
+
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let reader = BufReader::new(stdout);
+            for line in reader.lines().map_while(std::result::Result::ok) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+            let _ = child.wait();
+        });
+
+        Ok(rx)
+    }
+
     pub fn exec(&self, service: &str, command: &[&str]) -> Result<String> {
         let mut cmd = Command::new("docker");
         cmd.arg("compose")
@@ -1,80 +1,184 @@
+use crate::config::Endpoints;
+use crate::diagnostics;
+use crate::docker::compatibility;
+use crate::docker::compose::DockerCompose;
+use crate::docker::rpc::LightdClient;
+use crate::docker::retry::RetryPolicy;
 use crate::error::{Result, ZecDevError};
 use reqwest::Client;
-use indicatif::ProgressBar;
-use tokio::time::{sleep, Duration};
+use futures::future::join_all;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use tokio::time::Duration;
 use serde_json::Value;
-use std::net::TcpStream;
-use std::time::Duration as StdDuration;
+
+/// A service `wait_for_all` can drive readiness for. `Backend` carries the
+/// `--backend` flag value (`lwd`/`zaino`) since its readiness probe needs it.
+#[derive(Clone, Debug)]
+pub enum ServiceKind {
+    Zebra,
+    Faucet,
+    Backend(String),
+}
+
+impl ServiceKind {
+    pub(crate) fn label(&self) -> String {
+        match self {
+            ServiceKind::Zebra => "Zebra".to_string(),
+            ServiceKind::Faucet => "Faucet".to_string(),
+            ServiceKind::Backend(name) => {
+                if name == "lwd" { "Lightwalletd".to_string() } else { "Zaino".to_string() }
+            }
+        }
+    }
+}
+
+/// Backends are only considered caught up once their reported tip is within
+/// this many blocks of Zebra's - a freshly-connected backend can lag a
+/// block or two behind while it processes the latest compact block.
+const BACKEND_SYNC_TOLERANCE: u64 = 2;
 
 pub struct HealthChecker {
     client: Client,
-    max_retries: u32,
-    retry_delay: Duration,
-    backend_max_retries: u32,
+    retry_policy: RetryPolicy,
+    backend_retry_policy: RetryPolicy,
+    compose: Option<DockerCompose>,
+    endpoints: Endpoints,
 }
 
 impl HealthChecker {
     pub fn new() -> Self {
         Self {
             client: Client::new(),
-            max_retries: 560,
-            retry_delay: Duration::from_secs(2),
-            backend_max_retries: 600,
+            retry_policy: RetryPolicy::default(),
+            backend_retry_policy: RetryPolicy {
+                max_elapsed: Duration::from_secs(20 * 60),
+                ..RetryPolicy::default()
+            },
+            compose: None,
+            endpoints: Endpoints::default(),
         }
     }
 
-    pub async fn wait_for_zebra(&self, pb: &ProgressBar) -> Result<()> {
-        for i in 0..self.max_retries {
-            pb.tick();
-            
-            match self.check_zebra().await {
-                Ok(_) => return Ok(()),
-                Err(_) if i < self.max_retries - 1 => {
-                    sleep(self.retry_delay).await;
-                }
-                Err(e) => return Err(e),
-            }
+    pub fn with_policies(retry_policy: RetryPolicy, backend_retry_policy: RetryPolicy) -> Self {
+        Self {
+            client: Client::new(),
+            retry_policy,
+            backend_retry_policy,
+            compose: None,
+            endpoints: Endpoints::default(),
         }
+    }
 
-        Err(ZecDevError::ServiceNotReady("Zebra".into()))
+    /// Attach a `DockerCompose` handle so failed readiness checks can pull
+    /// and persist the failing service's logs for debugging.
+    pub fn with_compose(mut self, compose: DockerCompose) -> Self {
+        self.compose = Some(compose);
+        self
+    }
+
+    /// Override the default `127.0.0.1` host endpoints, e.g. when probing a
+    /// remote or remapped-port devnet.
+    pub fn with_endpoints(mut self, endpoints: Endpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    pub async fn wait_for_zebra(&self, pb: &ProgressBar) -> Result<()> {
+        let result = self.retry_policy.retry("Zebra", pb, || self.check_zebra()).await;
+        self.capture_on_failure(&result, "zebra");
+        result
     }
 
     pub async fn wait_for_faucet(&self, pb: &ProgressBar) -> Result<()> {
-        for i in 0..self.max_retries {
-            pb.tick();
-            
-            match self.check_faucet().await {
-                Ok(_) => return Ok(()),
-                Err(_) if i < self.max_retries - 1 => {
-                    sleep(self.retry_delay).await;
-                }
-                Err(e) => return Err(e),
-            }
+        let result = self.retry_policy.retry("Faucet", pb, || self.check_faucet()).await;
+        self.capture_on_failure(&result, "faucet");
+        result
+    }
+
+    pub async fn wait_for_backend(&self, backend: &str, pb: &ProgressBar) -> Result<()> {
+        let result = self
+            .backend_retry_policy
+            .retry(backend, pb, || self.check_backend(backend))
+            .await;
+        self.capture_on_failure(&result, if backend == "lwd" { "lightwalletd" } else { "zaino" });
+        result
+    }
+
+    /// On a failed readiness wait, pull and persist `compose_service`'s logs
+    /// so a timeout leaves behind a self-service debugging bundle instead of
+    /// just a terse error message. No-op if no `DockerCompose` is attached or
+    /// `result` succeeded.
+    fn capture_on_failure<T>(&self, result: &Result<T>, compose_service: &str) {
+        if result.is_ok() {
+            return;
         }
 
-        Err(ZecDevError::ServiceNotReady("Faucet".into()))
+        if let Some(compose) = &self.compose {
+            match diagnostics::capture_logs(compose, &[compose_service]) {
+                Ok(path) => println!("Captured {} logs to {}", compose_service, path.display()),
+                Err(e) => println!("Warning: could not capture diagnostics: {}", e),
+            }
+        }
     }
 
-    pub async fn wait_for_backend(&self, backend: &str, pb: &ProgressBar) -> Result<()> {
-        for i in 0..self.backend_max_retries {
-            pb.tick();
-            
-            match self.check_backend(backend).await {
-                Ok(_) => return Ok(()),
-                Err(_) if i < self.backend_max_retries - 1 => {
-                    sleep(self.retry_delay).await;
+    /// Wait for every `service` concurrently instead of one after another,
+    /// each driving its own child progress bar under `multi`. Returns a
+    /// combined error naming every service that failed rather than
+    /// aborting on the first one, so a user sees the full picture of what's
+    /// still lagging.
+    pub async fn wait_for_all(&self, services: &[ServiceKind], multi: &MultiProgress) -> Result<()> {
+        let style = ProgressStyle::default_spinner()
+            .template("{spinner:.green} {msg}")
+            .unwrap();
+
+        let checks = services.iter().map(|service| {
+            let pb = multi.add(ProgressBar::new_spinner());
+            pb.set_style(style.clone());
+            pb.set_message(format!("Waiting for {}...", service.label()));
+
+            async move {
+                let result = match service {
+                    ServiceKind::Zebra => self.wait_for_zebra(&pb).await,
+                    ServiceKind::Faucet => self.wait_for_faucet(&pb).await,
+                    ServiceKind::Backend(name) => self.wait_for_backend(name, &pb).await,
+                };
+
+                match &result {
+                    Ok(_) => {
+                        log::info!("health: {} ready", service.label());
+                        pb.finish_with_message(format!("{} ready", service.label()));
+                    }
+                    Err(e) => {
+                        log::error!("health: {} failed: {}", service.label(), e);
+                        pb.finish_with_message(format!("{} failed: {}", service.label(), e));
+                    }
                 }
-                Err(e) => return Err(e),
+
+                (service.label(), result)
             }
+        });
+
+        let results = join_all(checks).await;
+
+        // Each wait_for_* call above has already captured its own failing
+        // service's logs via `capture_on_failure`, so there's nothing left
+        // to do here but fold the individual errors into one combined one.
+        let failures: Vec<String> = results
+            .into_iter()
+            .filter_map(|(label, result)| result.err().map(|e| format!("{}: {}", label, e)))
+            .collect();
+
+        if failures.is_empty() {
+            return Ok(());
         }
 
-        Err(ZecDevError::ServiceNotReady(format!("{} not ready", backend)))
+        Err(ZecDevError::ServiceNotReady(failures.join("; ")))
     }
 
     async fn check_zebra(&self) -> Result<()> {
         let resp = self
             .client
-            .post("http://127.0.0.1:8232")
+            .post(&self.endpoints.zebra_rpc)
             .json(&serde_json::json!({
                 "jsonrpc": "2.0",
                 "id": "health",
@@ -95,7 +199,7 @@ impl HealthChecker {
     async fn check_faucet(&self) -> Result<()> {
         let resp = self
             .client
-            .get("http://127.0.0.1:8080/health")
+            .get(format!("{}/health", self.endpoints.faucet_http))
             .timeout(Duration::from_secs(5))
             .send()
             .await?;
@@ -114,24 +218,113 @@ impl HealthChecker {
     }
 
     async fn check_backend(&self, backend: &str) -> Result<()> {
-        // Zaino and Lightwalletd are gRPC services on port 9067
-        // They don't respond to HTTP, so we do a TCP connection check
-        
+        // Zaino and Lightwalletd both implement CompactTxStreamer, so a real
+        // readiness check is a GetLightdInfo call rather than a bare TCP
+        // connect - an open port only proves something is listening, not
+        // that it's actually serving block data.
         let backend_name = if backend == "lwd" { "lightwalletd" } else { "zaino" };
-        
-        // Try to connect to localhost:9067 with 2 second timeout
-        match TcpStream::connect_timeout(
-            &"127.0.0.1:9067".parse().unwrap(),
-            StdDuration::from_secs(2)
-        ) {
-            Ok(_) => {
-                // Port is open and accepting connections - backend is ready!
-                Ok(())
-            }
-            Err(_) => {
-                // Port not accepting connections yet
-                Err(ZecDevError::HealthCheck(format!("{} not ready", backend_name)))
+
+        let mut client = LightdClient::connect(&self.endpoints.backend_grpc)
+            .await
+            .map_err(|_| ZecDevError::HealthCheck(format!("{} not ready", backend_name)))?;
+
+        let info = client
+            .get_lightd_info()
+            .await
+            .map_err(|_| ZecDevError::HealthCheck(format!("{} not ready", backend_name)))?;
+
+        if info.block_height == 0 {
+            return Err(ZecDevError::HealthCheck(format!(
+                "{} reports block_height 0 (not synced yet)",
+                backend_name
+            )));
+        }
+
+        if let Ok(zebra_height) = self.get_zebra_block_count().await {
+            let lag = zebra_height.saturating_sub(info.block_height);
+            if lag > BACKEND_SYNC_TOLERANCE {
+                return Err(ZecDevError::HealthCheck(format!(
+                    "{} is {} blocks behind Zebra ({} vs {})",
+                    backend_name, lag, info.block_height, zebra_height
+                )));
             }
         }
+
+        Ok(())
+    }
+
+    /// Query Zebra's `getinfo` and the backend's `GetLightdInfo` for their
+    /// reported versions, validate each against `compatibility`'s supported
+    /// ranges, and return the detected strings for diagnostic display.
+    pub async fn check_versions(&self, backend: &str) -> Result<VersionReport> {
+        let zebra_version = self.get_zebra_version().await?;
+        compatibility::check_version("zebra", &zebra_version)?;
+
+        let backend_component = if backend == "lwd" { "lightwalletd" } else { "zaino" };
+        let mut backend_version = None;
+
+        if backend == "lwd" || backend == "zaino" {
+            let mut client = LightdClient::connect(&self.endpoints.backend_grpc).await?;
+            let info = client.get_lightd_info().await?;
+            compatibility::check_version(backend_component, &info.version)?;
+            backend_version = Some(info.version);
+        }
+
+        Ok(VersionReport {
+            zebra_version,
+            backend_name: backend_component.to_string(),
+            backend_version,
+        })
+    }
+
+    async fn get_zebra_version(&self) -> Result<String> {
+        let resp = self
+            .client
+            .post(&self.endpoints.zebra_rpc)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": "health",
+                "method": "getinfo",
+                "params": []
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await?;
+
+        let json: Value = resp.json().await?;
+
+        json.get("result")
+            .and_then(|r| r.get("build"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| ZecDevError::HealthCheck("Invalid getinfo response".into()))
     }
+
+    async fn get_zebra_block_count(&self) -> Result<u64> {
+        let resp = self
+            .client
+            .post(&self.endpoints.zebra_rpc)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": "health",
+                "method": "getblockcount",
+                "params": []
+            }))
+            .timeout(Duration::from_secs(5))
+            .send()
+            .await?;
+
+        let json: Value = resp.json().await?;
+
+        json.get("result")
+            .and_then(|v| v.as_u64())
+            .ok_or_else(|| ZecDevError::HealthCheck("Invalid block count response".into()))
+    }
+}
+
+/// Detected component versions from a successful [`HealthChecker::check_versions`] pass.
+pub struct VersionReport {
+    pub zebra_version: String,
+    pub backend_name: String,
+    pub backend_version: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+pub mod backend;
+pub mod compatibility;
+pub mod compose;
+pub mod health;
+pub mod regtest;
+pub mod retry;
+pub mod rpc;
@@ -0,0 +1,98 @@
+//! Regtest control primitives - block generation and faucet funding - shared
+//! by anything that needs to step the chain deterministically: `up`'s
+//! maturity wait, `fund`'s confirmation mining, the `generate`/`faucet`
+//! subcommands, and `serve`'s RPC handlers all call through here rather than
+//! each keeping their own copy of the same `getblockcount` polling loop.
+
+use crate::config::Endpoints;
+use crate::error::{Result, ZecDevError};
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::time::{sleep, Duration};
+
+/// Current Zebra block height via its `getblockcount` JSON-RPC method.
+pub async fn get_block_count(client: &Client, endpoints: &Endpoints) -> Result<u64> {
+    let resp = client
+        .post(&endpoints.zebra_rpc)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "id": "blockcount",
+            "method": "getblockcount",
+            "params": []
+        }))
+        .timeout(Duration::from_secs(5))
+        .send()
+        .await?;
+
+    let json: Value = resp.json().await?;
+
+    json.get("result")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| ZecDevError::HealthCheck("Invalid block count response".into()))
+}
+
+/// Wait for Zebra's internal miner to produce `count` more blocks than it
+/// had when this was called, polling `getblockcount` rather than triggering
+/// generation directly - Zebra's regtest miner runs continuously on its own,
+/// there is no `generate` RPC to call. Returns the final height reached.
+pub async fn mine_blocks(
+    client: &Client,
+    endpoints: &Endpoints,
+    count: u64,
+    timeout: Duration,
+) -> Result<u64> {
+    let start_height = get_block_count(client, endpoints).await?;
+    let target = start_height + count;
+    let start = std::time::Instant::now();
+
+    loop {
+        let height = get_block_count(client, endpoints).await?;
+        if height >= target {
+            return Ok(height);
+        }
+
+        if start.elapsed() > timeout {
+            return Err(ZecDevError::ServiceNotReady(format!(
+                "timed out waiting for {} blocks (reached {} of {})",
+                count, height, target
+            )));
+        }
+
+        sleep(Duration::from_secs(2)).await;
+    }
+}
+
+/// Request `amount` ZEC be sent to `address` through the faucet's HTTP API,
+/// returning the broadcast transaction id.
+pub async fn request_faucet_funds(
+    client: &Client,
+    endpoints: &Endpoints,
+    address: &str,
+    amount: f64,
+) -> Result<String> {
+    let resp = client
+        .post(format!("{}/request", endpoints.faucet_http))
+        .json(&json!({
+            "address": address,
+            "amount": amount
+        }))
+        .timeout(Duration::from_secs(45))
+        .send()
+        .await?;
+
+    if !resp.status().is_success() {
+        let error_text = resp.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(ZecDevError::HealthCheck(format!(
+            "faucet request failed: {}",
+            error_text
+        )));
+    }
+
+    let json: Value = resp.json().await?;
+
+    json.get("txid")
+        .and_then(|v| v.as_str())
+        .filter(|txid| !txid.is_empty())
+        .map(|txid| txid.to_string())
+        .ok_or_else(|| ZecDevError::HealthCheck("faucet response missing txid".into()))
+}
@@ -0,0 +1,94 @@
+use crate::error::{Result, ZecDevError};
+use indicatif::ProgressBar;
+use rand::Rng;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+/// Exponential backoff with full jitter, bounded by a total elapsed-time
+/// budget rather than a fixed retry count. On attempt `n` the delay is drawn
+/// uniformly from `[0, min(max_delay, base * multiplier^n))`, which avoids
+/// both thundering-herd polling and the flat stall of a fixed interval.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub base: Duration,
+    pub multiplier: f64,
+    pub max_delay: Duration,
+    pub max_elapsed: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(base: Duration, multiplier: f64, max_delay: Duration, max_elapsed: Duration) -> Self {
+        Self {
+            base,
+            multiplier,
+            max_delay,
+            max_elapsed,
+        }
+    }
+
+    fn jittered_delay(&self, attempt: u32) -> Duration {
+        let cap = Duration::from_secs_f64(
+            (self.base.as_secs_f64() * self.multiplier.powi(attempt as i32))
+                .min(self.max_delay.as_secs_f64()),
+        );
+        let jitter: f64 = rand::thread_rng().gen_range(0.0..1.0);
+        cap.mul_f64(jitter)
+    }
+
+    /// Poll `check` until it succeeds or `max_elapsed` is exceeded, ticking
+    /// `pb` on every attempt. On exhaustion, returns a `ServiceNotReady` that
+    /// names `label`, the attempt count, and the total time spent so the
+    /// error is actionable instead of a bare timeout.
+    pub async fn retry<F, Fut, T>(&self, label: &str, pb: &ProgressBar, mut check: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let start = Instant::now();
+        let mut attempt = 0u32;
+        let mut last_err: Option<ZecDevError> = None;
+
+        loop {
+            pb.tick();
+
+            match check().await {
+                Ok(value) => return Ok(value),
+                Err(e) => {
+                    last_err = Some(e);
+                }
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= self.max_elapsed {
+                break;
+            }
+
+            let delay = self.jittered_delay(attempt);
+            let remaining = self.max_elapsed.saturating_sub(elapsed);
+            sleep(delay.min(remaining)).await;
+            attempt += 1;
+        }
+
+        Err(ZecDevError::ServiceNotReady(format!(
+            "{} not ready after {} attempts ({:.1}s elapsed){}",
+            label,
+            attempt + 1,
+            start.elapsed().as_secs_f64(),
+            last_err
+                .map(|e| format!(": {}", e))
+                .unwrap_or_default()
+        )))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_millis(500),
+            multiplier: 1.6,
+            max_delay: Duration::from_secs(10),
+            max_elapsed: Duration::from_secs(20 * 60),
+        }
+    }
+}
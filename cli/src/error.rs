@@ -0,0 +1,42 @@
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, ZecDevError>;
+
+#[derive(Error, Debug)]
+pub enum ZecDevError {
+    #[error("Docker error: {0}")]
+    Docker(String),
+
+    #[error("Configuration error: {0}")]
+    Config(String),
+
+    #[error("Health check failed: {0}")]
+    HealthCheck(String),
+
+    #[error("Service not ready: {0}")]
+    ServiceNotReady(String),
+
+    #[error("gRPC error: {0}")]
+    Grpc(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("HTTP request error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl From<tonic::Status> for ZecDevError {
+    fn from(status: tonic::Status) -> Self {
+        ZecDevError::Grpc(status.message().to_string())
+    }
+}
+
+impl From<tonic::transport::Error> for ZecDevError {
+    fn from(err: tonic::transport::Error) -> Self {
+        ZecDevError::Grpc(err.to_string())
+    }
+}
@@ -0,0 +1,13 @@
+//! Reusable devnet-orchestration API. `main.rs` is a thin CLI front-end over
+//! these same modules, so anything that can drive the devnet from the
+//! command line - starting it, waiting on health, mining blocks, shielding
+//! funds - can also be driven in-process, from an integration test, or
+//! through `serve`'s RPC server rather than shelling out to `zeckit` and
+//! scraping stdout.
+
+pub mod commands;
+pub mod config;
+pub mod diagnostics;
+pub mod docker;
+pub mod error;
+pub mod logging;
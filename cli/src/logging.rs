@@ -0,0 +1,54 @@
+//! Persistent, rotating file log of every phase of a `zeckit` run.
+//!
+//! The console output (colored banners, carriage-return progress bars) stays
+//! exactly as it was - it's for a human watching the terminal. This adds a
+//! second, parallel sink: a timestamped `log` record of the same steps,
+//! warnings and RPC errors, written to a size-rotated `zeckit.debug.log` so a
+//! 100-minute startup that fails on CI leaves a trace to read after the fact.
+
+use log::LevelFilter;
+use log4rs::append::rolling_file::policy::compound::roll::fixed_window::FixedWindowRoller;
+use log4rs::append::rolling_file::policy::compound::trigger::size::SizeTrigger;
+use log4rs::append::rolling_file::policy::compound::CompoundPolicy;
+use log4rs::append::rolling_file::RollingFileAppender;
+use log4rs::config::{Appender, Config, Root};
+use log4rs::encode::pattern::PatternEncoder;
+
+const LOG_FILE: &str = "zeckit.debug.log";
+const LOG_FILE_ROLLED: &str = "zeckit.debug.{}.log.gz";
+const MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+const MAX_ROLLED_LOGS: u32 = 5;
+
+/// Parse `level` (from `--log-level`/`--verbose`) and initialize the global
+/// `log` logger to write to the rotating debug file. Safe to call once per
+/// process; a second call would fail to install and is ignored.
+pub fn init(level: &str) {
+    let level_filter = level.parse::<LevelFilter>().unwrap_or(LevelFilter::Info);
+
+    let trigger = SizeTrigger::new(MAX_LOG_BYTES);
+    let roller = match FixedWindowRoller::builder()
+        .build(LOG_FILE_ROLLED, MAX_ROLLED_LOGS)
+    {
+        Ok(roller) => roller,
+        Err(_) => return,
+    };
+    let policy = CompoundPolicy::new(Box::new(trigger), Box::new(roller));
+
+    let appender = match RollingFileAppender::builder()
+        .encoder(Box::new(PatternEncoder::new(
+            "{d(%Y-%m-%d %H:%M:%S%.3f)} {l:<5} [{t}] {m}{n}",
+        )))
+        .build(LOG_FILE, Box::new(policy))
+    {
+        Ok(appender) => appender,
+        Err(_) => return,
+    };
+
+    let config = Config::builder()
+        .appender(Appender::builder().build("debug_log", Box::new(appender)))
+        .build(Root::builder().appender("debug_log").build(level_filter));
+
+    if let Ok(config) = config {
+        let _ = log4rs::init_config(config);
+    }
+}
@@ -2,13 +2,11 @@ use clap::{Parser, Subcommand};
 use colored::*;
 use std::process;
 
-mod commands;
-mod docker;
-mod config;
-mod error;
 mod utils;
 
-use error::Result;
+use zeckit::commands;
+use zeckit::error::Result;
+use zeckit::logging;
 
 #[derive(Parser)]
 #[command(name = "zeckit")]
@@ -17,6 +15,14 @@ use error::Result;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Log level written to zeckit.debug.log (error, warn, info, debug, trace)
+    #[arg(long, global = true, default_value = "info")]
+    log_level: String,
+
+    /// Shorthand for --log-level debug
+    #[arg(short, long, global = true)]
+    verbose: bool,
 }
 
 #[derive(Subcommand)]
@@ -26,38 +32,70 @@ enum Commands {
         /// Light-client backend: lwd (lightwalletd) or zaino
         #[arg(short, long, default_value = "none")]
         backend: String,
-        
+
         /// Force fresh start (remove volumes)
         #[arg(short, long)]
         fresh: bool,
     },
-    
+
+    /// Shield matured coinbase into the Sapling and Orchard pools
+    Fund,
+
     /// Stop the ZecKit devnet
     Down {
         /// Remove volumes (clean slate)
         #[arg(short, long)]
         purge: bool,
     },
-    
+
     /// Show devnet status
     Status,
-    
+
     /// Run smoke tests
     Test {
         /// Run golden E2E flow instead of smoke tests
         #[arg(long)]
         golden: bool,
     },
+
+    /// Expose devnet control over a local JSON-RPC/HTTP server
+    Serve {
+        /// Port to listen on
+        #[arg(short, long, default_value_t = 7070)]
+        port: u16,
+    },
+
+    /// Mine exactly N blocks and wait for them to confirm
+    Generate {
+        /// Number of blocks to mine
+        count: u64,
+    },
+
+    /// Request faucet funds for an address
+    Faucet {
+        /// Recipient address
+        address: String,
+
+        /// Amount of ZEC to request
+        amount: f64,
+    },
 }
 
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
-    
+
+    let log_level = if cli.verbose { "debug" } else { cli.log_level.as_str() };
+    logging::init(log_level);
+    log::info!("zeckit starting up");
+
     let result = match cli.command {
         Commands::Up { backend, fresh } => {
             commands::up::execute(backend, fresh).await
         }
+        Commands::Fund => {
+            commands::fund::execute().await
+        }
         Commands::Down { purge } => {
             commands::down::execute(purge).await
         }
@@ -67,10 +105,22 @@ async fn main() {
         Commands::Test { golden } => {
             commands::test::execute(golden).await
         }
+        Commands::Serve { port } => {
+            commands::serve::execute(port).await
+        }
+        Commands::Generate { count } => {
+            commands::generate::execute(count).await
+        }
+        Commands::Faucet { address, amount } => {
+            commands::faucet::execute(address, amount).await
+        }
     };
-    
+
     if let Err(e) = result {
+        log::error!("zeckit exiting with error: {}", e);
         eprintln!("{} {}", "Error:".red().bold(), e);
         process::exit(1);
     }
-}
\ No newline at end of file
+
+    log::info!("zeckit finished successfully");
+}